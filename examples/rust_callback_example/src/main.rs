@@ -0,0 +1,108 @@
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+
+#[repr(C)]
+pub struct HarmonicGraph {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Producer {
+    _private: [u8; 0],
+}
+
+/// Loss and throughput for the epoch just completed, handed to every fired callback.
+#[repr(C)]
+pub struct harmonics_metrics_t {
+    pub loss: f64,
+    pub throughput: f64,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum harmonics_control_t {
+    HARMONICS_CONTROL_CONTINUE = 0,
+    HARMONICS_CONTROL_CHECKPOINT = 1,
+    HARMONICS_CONTROL_STOP = 2,
+}
+
+pub type HarmonicsEpochCallback = unsafe extern "C" fn(
+    epoch: usize,
+    metrics: *const harmonics_metrics_t,
+    ctx: *mut c_void,
+) -> harmonics_control_t;
+
+#[link(name = "harmonics_ffi")]
+extern "C" {
+    fn harmonics_parse_graph(src: *const c_char) -> *mut HarmonicGraph;
+    fn harmonics_destroy_graph(g: *mut HarmonicGraph);
+    fn harmonics_create_csv_producer(path: *const c_char) -> *mut Producer;
+    fn harmonics_destroy_producer(p: *mut Producer);
+    fn harmonics_bind_producer(g: *mut HarmonicGraph, name: *const c_char, p: *mut Producer);
+    fn harmonics_register_callback(
+        g: *mut HarmonicGraph,
+        start_epoch: usize,
+        every_n_epochs: usize,
+        cb: HarmonicsEpochCallback,
+        ctx: *mut c_void,
+    );
+    fn harmonics_fit(g: *mut HarmonicGraph, epochs: usize);
+}
+
+struct EarlyStopping {
+    best_loss: f64,
+    patience: usize,
+    bad_epochs: usize,
+}
+
+unsafe extern "C" fn early_stopping_cb(
+    epoch: usize,
+    metrics: *const harmonics_metrics_t,
+    ctx: *mut c_void,
+) -> harmonics_control_t {
+    let state = &mut *(ctx as *mut EarlyStopping);
+    let loss = (*metrics).loss;
+    println!("epoch {epoch}: loss={loss} throughput={}", (*metrics).throughput);
+
+    if loss < state.best_loss {
+        state.best_loss = loss;
+        state.bad_epochs = 0;
+        return harmonics_control_t::HARMONICS_CONTROL_CHECKPOINT;
+    }
+    state.bad_epochs += 1;
+    if state.bad_epochs >= state.patience {
+        return harmonics_control_t::HARMONICS_CONTROL_STOP;
+    }
+    harmonics_control_t::HARMONICS_CONTROL_CONTINUE
+}
+
+fn main() {
+    let graph_src = CString::new("producer d{1}; consumer c; cycle{ d -> c; }").unwrap();
+    let csv_path = CString::new("train.csv").unwrap();
+    let input = CString::new("d").unwrap();
+
+    let mut state = EarlyStopping {
+        best_loss: f64::INFINITY,
+        patience: 3,
+        bad_epochs: 0,
+    };
+
+    unsafe {
+        let graph = harmonics_parse_graph(graph_src.as_ptr());
+        let data = harmonics_create_csv_producer(csv_path.as_ptr());
+        harmonics_bind_producer(graph, input.as_ptr(), data);
+
+        harmonics_register_callback(
+            graph,
+            0,
+            1,
+            early_stopping_cb,
+            &mut state as *mut EarlyStopping as *mut c_void,
+        );
+        harmonics_fit(graph, 50);
+
+        harmonics_destroy_producer(data);
+        harmonics_destroy_graph(graph);
+    }
+}
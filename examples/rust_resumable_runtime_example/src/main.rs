@@ -0,0 +1,79 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::thread;
+use std::time::Duration;
+
+#[repr(C)]
+pub struct HarmonicGraph {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Producer {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct CycleRuntime {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum harmonics_state_t {
+    HARMONICS_STATE_RUNNING = 0,
+    HARMONICS_STATE_SUSPENDED = 1,
+    HARMONICS_STATE_BLOCKED = 2,
+    HARMONICS_STATE_FINISHED = 3,
+}
+
+#[link(name = "harmonics_ffi")]
+extern "C" {
+    fn harmonics_parse_graph(src: *const c_char) -> *mut HarmonicGraph;
+    fn harmonics_destroy_graph(g: *mut HarmonicGraph);
+    fn harmonics_create_csv_producer(path: *const c_char) -> *mut Producer;
+    fn harmonics_destroy_producer(p: *mut Producer);
+    fn harmonics_bind_producer(g: *mut HarmonicGraph, name: *const c_char, p: *mut Producer);
+    fn harmonics_create_cycle_runtime(g: *mut HarmonicGraph, epochs: usize) -> *mut CycleRuntime;
+    fn harmonics_destroy_cycle_runtime(rt: *mut CycleRuntime);
+    fn harmonics_cycle_step(rt: *mut CycleRuntime) -> harmonics_state_t;
+    fn harmonics_cycle_resume(rt: *mut CycleRuntime);
+}
+
+/// Drive a `CycleRuntime` to completion, yielding the thread whenever the runtime
+/// voluntarily suspends at an epoch boundary, and waiting on a bound producer when blocked.
+fn run_to_completion(rt: *mut CycleRuntime) {
+    loop {
+        match unsafe { harmonics_cycle_step(rt) } {
+            harmonics_state_t::HARMONICS_STATE_RUNNING => continue,
+            harmonics_state_t::HARMONICS_STATE_SUSPENDED => {
+                thread::yield_now();
+                unsafe { harmonics_cycle_resume(rt) };
+            }
+            harmonics_state_t::HARMONICS_STATE_BLOCKED => {
+                thread::sleep(Duration::from_millis(10));
+                unsafe { harmonics_cycle_resume(rt) };
+            }
+            harmonics_state_t::HARMONICS_STATE_FINISHED => break,
+        }
+    }
+}
+
+fn main() {
+    let graph_src = CString::new("producer d{1}; consumer c; cycle{ d -> c; }").unwrap();
+    let csv_path = CString::new("train.csv").unwrap();
+    let input = CString::new("d").unwrap();
+    unsafe {
+        let graph = harmonics_parse_graph(graph_src.as_ptr());
+        let data = harmonics_create_csv_producer(csv_path.as_ptr());
+        harmonics_bind_producer(graph, input.as_ptr(), data);
+
+        let rt = harmonics_create_cycle_runtime(graph, 5);
+        run_to_completion(rt);
+        harmonics_destroy_cycle_runtime(rt);
+
+        harmonics_destroy_producer(data);
+        harmonics_destroy_graph(graph);
+    }
+}
@@ -39,13 +39,16 @@ extern "C" {
         count: usize,
     ) -> *mut *mut HarmonicGraph;
     fn harmonics_destroy_partitions(parts: *mut *mut HarmonicGraph, count: usize);
+    fn harmonics_partition_memory_plan(part: *mut HarmonicGraph) -> usize;
     fn harmonics_create_distributed_scheduler(
         parts: *mut *mut HarmonicGraph,
         count: usize,
         backends: *const harmonics_backend_t,
         secure: bool,
+        alias_buffers: bool,
     ) -> *mut DistributedScheduler;
     fn harmonics_destroy_distributed_scheduler(s: *mut DistributedScheduler);
+    fn harmonics_scheduler_set_threads(s: *mut DistributedScheduler, count: usize);
     fn harmonics_scheduler_bind_producer(
         s: *mut DistributedScheduler,
         part: usize,
@@ -64,8 +67,14 @@ fn main() {
         let g = harmonics_parse_graph(src.as_ptr());
         let backends = [harmonics_backend_t::HARMONICS_BACKEND_CPU, harmonics_backend_t::HARMONICS_BACKEND_CPU];
         let parts = harmonics_auto_partition(g, backends.as_ptr(), backends.len());
-        let sched = harmonics_create_distributed_scheduler(parts, backends.len(), backends.as_ptr(), false);
+        for i in 0..backends.len() {
+            let bytes = harmonics_partition_memory_plan(*parts.add(i));
+            println!("partition {i} memory plan: {bytes} bytes");
+        }
+        let sched =
+            harmonics_create_distributed_scheduler(parts, backends.len(), backends.as_ptr(), false, true);
         harmonics_destroy_partitions(parts, backends.len());
+        harmonics_scheduler_set_threads(sched, 0);
         let dummy_path = CString::new("train.csv").unwrap();
         // Producer creation omitted for brevity
         harmonics_scheduler_fit(sched, 1);
@@ -0,0 +1,155 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::slice;
+
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+#[repr(C)]
+pub struct HarmonicGraph {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub enum harmonics_backend_t {
+    HARMONICS_BACKEND_CPU = 0,
+    HARMONICS_BACKEND_GPU = 1,
+    HARMONICS_BACKEND_FPGA = 2,
+    HARMONICS_BACKEND_WASM = 3,
+    HARMONICS_BACKEND_AUTO = 4,
+}
+
+#[repr(C)]
+pub struct harmonics_buffer_t {
+    data: *mut u8,
+    len: usize,
+}
+
+#[link(name = "harmonics_ffi")]
+extern "C" {
+    fn harmonics_parse_graph(src: *const c_char) -> *mut HarmonicGraph;
+    fn harmonics_destroy_graph(g: *mut HarmonicGraph);
+    fn harmonics_auto_partition(
+        g: *const HarmonicGraph,
+        backends: *const harmonics_backend_t,
+        count: usize,
+    ) -> *mut *mut HarmonicGraph;
+    fn harmonics_destroy_partitions(parts: *mut *mut HarmonicGraph, count: usize);
+    fn harmonics_serialize_graph(g: *const HarmonicGraph) -> harmonics_buffer_t;
+    fn harmonics_deserialize_graph(bytes: *const u8, len: usize) -> *mut HarmonicGraph;
+    fn harmonics_serialize_partitions(
+        parts: *const *mut HarmonicGraph,
+        count: usize,
+    ) -> harmonics_buffer_t;
+    fn harmonics_deserialize_partitions(
+        bytes: *const u8,
+        len: usize,
+        count_out: *mut usize,
+    ) -> *mut *mut HarmonicGraph;
+    fn harmonics_free_buffer(buf: harmonics_buffer_t);
+}
+
+pub struct Graph(*mut HarmonicGraph);
+
+impl Graph {
+    pub fn parse(src: &str) -> Self {
+        let src = CString::new(src).unwrap();
+        Graph(unsafe { harmonics_parse_graph(src.as_ptr()) })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        unsafe {
+            let buf = harmonics_serialize_graph(self.0);
+            let bytes = slice::from_raw_parts(buf.data, buf.len).to_vec();
+            harmonics_free_buffer(buf);
+            bytes
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let ptr = unsafe { harmonics_deserialize_graph(bytes.as_ptr(), bytes.len()) };
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Graph(ptr))
+    }
+
+    pub fn as_ptr(&self) -> *mut HarmonicGraph {
+        self.0
+    }
+}
+
+impl Drop for Graph {
+    fn drop(&mut self) {
+        unsafe { harmonics_destroy_graph(self.0) };
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Graph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Graph {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = <Vec<u8>>::deserialize(deserializer)?;
+        Graph::from_bytes(&bytes).ok_or_else(|| D::Error::custom("harmonics_deserialize_graph returned null"))
+    }
+}
+
+pub struct PartitionPlan {
+    ptr: *mut *mut HarmonicGraph,
+    count: usize,
+}
+
+impl PartitionPlan {
+    /// # Safety
+    /// `ptr` must point to `count` valid entries, not freed elsewhere.
+    pub unsafe fn from_raw(ptr: *mut *mut HarmonicGraph, count: usize) -> Self {
+        PartitionPlan { ptr, count }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        unsafe {
+            let buf = harmonics_serialize_partitions(self.ptr, self.count);
+            let bytes = slice::from_raw_parts(buf.data, buf.len).to_vec();
+            harmonics_free_buffer(buf);
+            bytes
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut count = 0usize;
+        let ptr = unsafe { harmonics_deserialize_partitions(bytes.as_ptr(), bytes.len(), &mut count) };
+        if ptr.is_null() {
+            return None;
+        }
+        Some(PartitionPlan { ptr, count })
+    }
+}
+
+impl Drop for PartitionPlan {
+    fn drop(&mut self) {
+        unsafe { harmonics_destroy_partitions(self.ptr, self.count) };
+    }
+}
+
+fn main() {
+    let graph = Graph::parse("producer d{1}; consumer c; cycle{ d -> c; }");
+    let bytes = graph.to_bytes();
+
+    let reloaded = Graph::from_bytes(&bytes).expect("round-tripped graph should deserialize");
+
+    let backends = [harmonics_backend_t::HARMONICS_BACKEND_CPU];
+    unsafe {
+        let parts = harmonics_auto_partition(reloaded.as_ptr(), backends.as_ptr(), backends.len());
+        let plan = PartitionPlan::from_raw(parts, backends.len());
+        let plan_bytes = plan.to_bytes();
+        let _restored = PartitionPlan::from_bytes(&plan_bytes).expect("round-tripped plan should deserialize");
+    }
+}
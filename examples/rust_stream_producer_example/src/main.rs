@@ -0,0 +1,145 @@
+use std::ffi::CString;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+#[repr(C)]
+pub struct HarmonicGraph {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Producer {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub enum harmonics_dtype_t {
+    HARMONICS_DTYPE_F32 = 0,
+    HARMONICS_DTYPE_F64 = 1,
+    HARMONICS_DTYPE_I32 = 2,
+    HARMONICS_DTYPE_U8 = 3,
+}
+
+pub type HarmonicsReadCallback =
+    unsafe extern "C" fn(ctx: *mut c_void, buf: *mut u8, cap: usize) -> usize;
+
+#[link(name = "harmonics_ffi")]
+extern "C" {
+    fn harmonics_parse_graph(src: *const c_char) -> *mut HarmonicGraph;
+    fn harmonics_destroy_graph(g: *mut HarmonicGraph);
+    fn harmonics_create_stream_producer(
+        read_cb: HarmonicsReadCallback,
+        ctx: *mut c_void,
+        tensor_shape: *const usize,
+        shape_len: usize,
+        dtype: harmonics_dtype_t,
+    ) -> *mut Producer;
+    fn harmonics_producer_reset(p: *mut Producer);
+    fn harmonics_destroy_producer(p: *mut Producer);
+    fn harmonics_bind_producer(g: *mut HarmonicGraph, name: *const c_char, p: *mut Producer);
+    fn harmonics_fit(g: *mut HarmonicGraph, epochs: usize);
+}
+
+struct ReaderCtx<R> {
+    reader: R,
+    last_error: Option<io::Error>,
+}
+
+unsafe extern "C" fn read_trampoline<R: Read>(ctx: *mut c_void, buf: *mut u8, cap: usize) -> usize {
+    let ctx = &mut *(ctx as *mut ReaderCtx<R>);
+    let out = slice::from_raw_parts_mut(buf, cap);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| loop {
+        match ctx.reader.read(out) {
+            Ok(n) => return Ok(n),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }));
+    match result {
+        Ok(Ok(n)) => n,
+        Ok(Err(e)) => {
+            ctx.last_error = Some(e);
+            0
+        }
+        Err(_) => {
+            ctx.last_error = Some(io::Error::other("read callback panicked"));
+            0
+        }
+    }
+}
+
+/// Idiomatic wrapper around `harmonics_create_stream_producer` that pulls bytes from any
+/// `Read + Seek` impl instead of staging a CSV file on disk.
+pub struct StreamProducer<R: Read + Seek> {
+    ptr: *mut Producer,
+    ctx: Box<ReaderCtx<R>>,
+}
+
+impl<R: Read + Seek> StreamProducer<R> {
+    pub fn from_reader(reader: R, tensor_shape: &[usize], dtype: harmonics_dtype_t) -> Self {
+        let mut ctx = Box::new(ReaderCtx {
+            reader,
+            last_error: None,
+        });
+        let ctx_ptr = ctx.as_mut() as *mut ReaderCtx<R> as *mut c_void;
+        let ptr = unsafe {
+            harmonics_create_stream_producer(
+                read_trampoline::<R>,
+                ctx_ptr,
+                tensor_shape.as_ptr(),
+                tensor_shape.len(),
+                dtype,
+            )
+        };
+        StreamProducer { ptr, ctx }
+    }
+
+    /// Rewind the stream so the next `fit` epoch reads from the start again.
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.ctx.reader.seek(SeekFrom::Start(0))?;
+        unsafe { harmonics_producer_reset(self.ptr) };
+        Ok(())
+    }
+
+    /// The most recent I/O error the read callback hit, if any, since errors can't be
+    /// distinguished from clean end-of-epoch over the `size_t`-returning C callback.
+    pub fn last_error(&self) -> Option<&io::Error> {
+        self.ctx.last_error.as_ref()
+    }
+
+    pub fn as_ptr(&self) -> *mut Producer {
+        self.ptr
+    }
+}
+
+impl<R: Read + Seek> Drop for StreamProducer<R> {
+    fn drop(&mut self) {
+        unsafe { harmonics_destroy_producer(self.ptr) };
+    }
+}
+
+fn main() {
+    let graph_src = CString::new("producer d{1}; consumer c; cycle{ d -> c; }").unwrap();
+    let input = CString::new("d").unwrap();
+    let mut producer = StreamProducer::from_reader(
+        Cursor::new(vec![0u8; 64]),
+        &[1],
+        harmonics_dtype_t::HARMONICS_DTYPE_F32,
+    );
+    unsafe {
+        let graph = harmonics_parse_graph(graph_src.as_ptr());
+        harmonics_bind_producer(graph, input.as_ptr(), producer.as_ptr());
+        harmonics_fit(graph, 5);
+        producer.reset().expect("stream producer should rewind for the next epoch");
+        harmonics_fit(graph, 5);
+        if let Some(err) = producer.last_error() {
+            eprintln!("stream producer read error: {err}");
+        }
+        drop(producer);
+        harmonics_destroy_graph(graph);
+    }
+}